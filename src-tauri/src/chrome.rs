@@ -0,0 +1,35 @@
+//! Window-chrome commands backing the HTML titlebar used in frameless mode on
+//! Windows/Linux (macOS keeps the native overlay titlebar from `TitleBarStyle::Overlay`).
+
+use tauri::{AppHandle, Manager};
+
+fn main_window(app: &AppHandle) -> Result<tauri::WebviewWindow, String> {
+    app.get_webview_window("main")
+        .ok_or_else(|| "main window not found".to_string())
+}
+
+/// Starts an OS-native window drag from the titlebar region the frontend designates.
+#[tauri::command]
+pub fn start_dragging(app: AppHandle) -> Result<(), String> {
+    main_window(&app)?.start_dragging().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn minimize_window(app: AppHandle) -> Result<(), String> {
+    main_window(&app)?.minimize().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn toggle_maximize_window(app: AppHandle) -> Result<(), String> {
+    let window = main_window(&app)?;
+    if window.is_maximized().map_err(|e| e.to_string())? {
+        window.unmaximize().map_err(|e| e.to_string())
+    } else {
+        window.maximize().map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+pub fn close_window(app: AppHandle) -> Result<(), String> {
+    main_window(&app)?.close().map_err(|e| e.to_string())
+}
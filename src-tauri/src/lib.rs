@@ -1,4 +1,4 @@
-use tauri::Manager;
+use tauri::{Emitter, Manager};
 
 #[cfg(target_os = "macos")]
 use tauri::{TitleBarStyle, WebviewUrl, WebviewWindowBuilder};
@@ -9,6 +9,19 @@ use tauri::{WebviewUrl, WebviewWindowBuilder};
 #[cfg(mobile)]
 use tauri::{WebviewUrl, WebviewWindowBuilder};
 
+#[cfg(not(mobile))]
+use tauri::WindowEvent;
+
+mod chrome;
+mod presenter;
+mod theme;
+#[cfg(not(mobile))]
+mod tray;
+mod window_state;
+
+#[cfg(not(mobile))]
+use window_state::WindowState;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -19,32 +32,91 @@ pub fn run() {
                 .level(log::LevelFilter::Info)
                 .build(),
         )
+        .invoke_handler(tauri::generate_handler![
+            presenter::present,
+            presenter::update_presentation,
+            chrome::start_dragging,
+            chrome::minimize_window,
+            chrome::toggle_maximize_window,
+            chrome::close_window,
+            theme::set_theme
+        ])
         .setup(|_app| {
+            #[cfg(not(mobile))]
+            _app.manage(window_state::GeometryCache::default());
+
+            // Start hidden in the tray when launched with `--hidden`, e.g. from a login item.
+            #[cfg(not(mobile))]
+            let start_hidden = std::env::args().any(|arg| arg == "--hidden");
+
+            // Windows/Linux keep the native frame by default: there's no HTML titlebar, drag
+            // region, or min/max/close buttons shipped yet, so a decorationless window would
+            // be unmovable and unclosable. `--frameless` opts into the branded chrome early.
+            #[cfg(all(not(target_os = "macos"), not(mobile)))]
+            let frameless = std::env::args().any(|arg| arg == "--frameless");
+
+            // Follow the saved theme preference (or the OS setting, if it's `Auto`).
+            #[cfg(not(mobile))]
+            let theme_preference = window_state::load_theme(_app.handle());
+
             // Create main window with transparent titlebar on macOS
             #[cfg(target_os = "macos")]
             {
-                let win_builder = WebviewWindowBuilder::new(_app, "main", WebviewUrl::default())
+                let saved_state = window_state::load(_app.handle())
+                    .filter(|state| window_state::is_on_screen(_app.handle(), &state.position));
+
+                let mut win_builder = WebviewWindowBuilder::new(_app, "main", WebviewUrl::default())
                     .title("")
                     .inner_size(1024.0, 768.0)
                     .min_inner_size(400.0, 300.0)
                     .resizable(true)
-                    .center()
+                    .visible(!start_hidden)
+                    .theme(theme_preference.to_tauri_theme())
                     .title_bar_style(TitleBarStyle::Overlay);
 
-                win_builder.build()?;
+                win_builder = match &saved_state {
+                    Some(state) => win_builder
+                        .position(state.position.x as f64, state.position.y as f64)
+                        .inner_size(state.size.width as f64, state.size.height as f64),
+                    None => win_builder.center(),
+                };
+
+                let window = win_builder.build()?;
+                if saved_state.is_some_and(|state| state.maximized) {
+                    window.maximize()?;
+                }
+                watch_window_state(&window);
+                tray::build(_app.handle())?;
             }
 
             // Create main window on desktop platforms (not macOS, not mobile)
             #[cfg(all(not(target_os = "macos"), not(mobile)))]
             {
-                let win_builder = WebviewWindowBuilder::new(_app, "main", WebviewUrl::default())
+                let saved_state = window_state::load(_app.handle())
+                    .filter(|state| window_state::is_on_screen(_app.handle(), &state.position));
+
+                let mut win_builder = WebviewWindowBuilder::new(_app, "main", WebviewUrl::default())
                     .title("KC Songbook")
                     .inner_size(1024.0, 768.0)
                     .min_inner_size(400.0, 300.0)
                     .resizable(true)
-                    .center();
+                    .visible(!start_hidden)
+                    .decorations(!frameless)
+                    .theme(theme_preference.to_tauri_theme());
 
-                win_builder.build()?;
+                win_builder = match &saved_state {
+                    Some(state) => win_builder
+                        .position(state.position.x as f64, state.position.y as f64)
+                        .inner_size(state.size.width as f64, state.size.height as f64),
+                    None => win_builder.center(),
+                };
+
+                let window = win_builder.build()?;
+                if saved_state.is_some_and(|state| state.maximized) {
+                    window.maximize()?;
+                }
+                watch_window_state(&window);
+                tray::build(_app.handle())?;
             }
 
             // Mobile platforms (iOS, Android) - use minimal config
@@ -59,3 +131,70 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+/// Wires up the window so its geometry is saved to disk when it's about to close, so the next
+/// launch can restore it; also forwards OS theme switches to the frontend while the user has
+/// the `Auto` theme preference selected.
+#[cfg(not(mobile))]
+fn watch_window_state(window: &tauri::WebviewWindow) {
+    let window = window.clone();
+
+    window.clone().on_window_event(move |event| match event {
+        // Cache geometry in memory on every move/resize (cheap) instead of hitting disk on
+        // every frame of a drag; only write the file once, on exit. Skip caching while
+        // maximized so we remember the pre-maximize bounds, not the maximized ones.
+        WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+            if matches!(window.is_maximized(), Ok(false)) {
+                if let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) {
+                    let cache = window.state::<window_state::GeometryCache>();
+                    *cache.0.lock().unwrap() = Some((position, size));
+                }
+            }
+        }
+        WindowEvent::CloseRequested { .. } => persist_window_state(&window),
+        WindowEvent::ThemeChanged(os_theme) => {
+            let app = window.app_handle();
+            if window_state::load_theme(app) == window_state::ThemePreference::Auto {
+                let _ = app.emit(
+                    theme::THEME_CHANGED_EVENT,
+                    theme::ThemeChangedPayload { theme: *os_theme },
+                );
+            }
+        }
+        _ => {}
+    });
+}
+
+/// Flushes the cached geometry for the main window to disk. Called from the tray's Quit item,
+/// since `AppHandle::exit` tears the app down without firing `CloseRequested`; the native
+/// close button saves through the same `persist_window_state` from [`watch_window_state`].
+#[cfg(not(mobile))]
+pub(crate) fn flush_window_state(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        persist_window_state(&window);
+    }
+}
+
+#[cfg(not(mobile))]
+fn persist_window_state(window: &tauri::WebviewWindow) {
+    let Ok(maximized) = window.is_maximized() else {
+        return;
+    };
+
+    let cache = window.state::<window_state::GeometryCache>();
+    let cached = *cache.0.lock().unwrap();
+    let geometry = cached.or_else(|| window.outer_position().ok().zip(window.inner_size().ok()));
+
+    let Some((position, size)) = geometry else {
+        return;
+    };
+
+    window_state::save(
+        &window.app_handle().clone(),
+        &WindowState {
+            position,
+            size,
+            maximized,
+        },
+    );
+}
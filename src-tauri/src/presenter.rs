@@ -0,0 +1,66 @@
+//! The "presenter" window mirrors the currently selected song full-screen on a second
+//! monitor, for projection during services or rehearsals.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
+
+const PRESENTER_LABEL: &str = "presenter";
+const PRESENTATION_UPDATE_EVENT: &str = "presentation-update";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresentationUpdate {
+    pub song_title: String,
+    pub verses: Vec<String>,
+    pub verse_index: usize,
+}
+
+/// Opens the presenter window full-screen on `monitor_index` (or, if omitted, the second
+/// monitor in `available_monitors()` order), ready to receive the `presentation-update`
+/// events that `update_presentation` sends on behalf of the main window.
+#[tauri::command]
+pub fn present(app: AppHandle, monitor_index: Option<usize>) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(PRESENTER_LABEL) {
+        window.set_focus().map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    let monitors = app.available_monitors().map_err(|e| e.to_string())?;
+    // Default to the second attached monitor (the projector), falling back to the
+    // first listed one if there's only a single display.
+    let monitor = match monitor_index {
+        Some(i) => monitors
+            .get(i)
+            .ok_or_else(|| format!("no monitor at index {i}"))?,
+        None => monitors
+            .get(1)
+            .or_else(|| monitors.first())
+            .ok_or("no monitors available")?,
+    };
+
+    let position = monitor.position();
+    let size = monitor.size();
+
+    let window = WebviewWindowBuilder::new(&app, PRESENTER_LABEL, WebviewUrl::App("/present".into()))
+        .title("KC Songbook — Presenter")
+        .decorations(false)
+        .position(position.x as f64, position.y as f64)
+        .inner_size(size.width as f64, size.height as f64)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    window.set_fullscreen(true).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Pushes the current song and verse index to the presenter window, if it's open.
+#[tauri::command]
+pub fn update_presentation(app: AppHandle, update: PresentationUpdate) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(PRESENTER_LABEL) {
+        window
+            .emit(PRESENTATION_UPDATE_EVENT, update)
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
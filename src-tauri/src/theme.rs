@@ -0,0 +1,37 @@
+//! Lets the user force light/dark/auto appearance, and notifies the frontend of the
+//! effective theme whenever it changes — either because the user forced one, or because
+//! the OS switched appearance while `Auto` is selected.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Theme};
+
+use crate::window_state::{self, ThemePreference};
+
+pub const THEME_CHANGED_EVENT: &str = "theme-changed";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThemeChangedPayload {
+    pub theme: Theme,
+}
+
+/// Forces the main window to light, dark, or auto (OS-following) appearance and persists
+/// the choice so it survives a restart.
+#[tauri::command]
+pub fn set_theme(app: AppHandle, theme: ThemePreference) -> Result<(), String> {
+    window_state::save_theme(&app, theme);
+
+    let Some(window) = app.get_webview_window("main") else {
+        return Ok(());
+    };
+
+    window
+        .set_theme(theme.to_tauri_theme())
+        .map_err(|e| e.to_string())?;
+
+    let effective = match theme.to_tauri_theme() {
+        Some(t) => t,
+        None => window.theme().map_err(|e| e.to_string())?,
+    };
+    app.emit(THEME_CHANGED_EVENT, ThemeChangedPayload { theme: effective })
+        .map_err(|e| e.to_string())
+}
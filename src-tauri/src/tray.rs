@@ -0,0 +1,42 @@
+//! System tray icon so the app can run in the background after a `--hidden` launch.
+
+use tauri::{
+    menu::{Menu, MenuItem},
+    tray::TrayIconBuilder,
+    AppHandle, Manager,
+};
+
+/// Registers the tray icon with a Show/Quit menu. Clicking Show re-displays the main window
+/// (useful after starting hidden); Quit flushes window state to disk and exits the app entirely.
+pub fn build(app: &AppHandle) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &quit])?;
+
+    let mut tray_builder = TrayIconBuilder::new().menu(&menu).on_menu_event(|app, event| {
+        match event.id().as_ref() {
+            "show" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+            "quit" => {
+                // `AppHandle::exit` tears the app down without firing `CloseRequested`, so
+                // flush the cached window geometry here too, not just on the native close button.
+                crate::flush_window_state(app);
+                app.exit(0);
+            }
+            _ => {}
+        }
+    });
+
+    // Not every bundle configures an icon; skip it rather than crashing setup on launch.
+    if let Some(icon) = app.default_window_icon() {
+        tray_builder = tray_builder.icon(icon.clone());
+    }
+
+    tray_builder.build(app)?;
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+//! Persists the main window's geometry (position, size, maximized flag) and theme preference
+//! across launches.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize};
+
+const WINDOW_STATE_FILE: &str = "window-state.json";
+const THEME_FILE: &str = "theme.json";
+
+/// App-managed state caching the window's last known *non-maximized* geometry in memory, so
+/// a drag/resize doesn't hit disk on every frame and maximizing doesn't clobber the bounds
+/// we'd want to restore on "un-maximize". Written on `Moved`/`Resized`, flushed to disk on exit.
+#[derive(Default)]
+pub struct GeometryCache(pub Mutex<Option<(PhysicalPosition<i32>, PhysicalSize<u32>)>>);
+
+/// The user's forced appearance, or `Auto` to follow the OS setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreference {
+    Light,
+    Dark,
+    #[default]
+    Auto,
+}
+
+impl ThemePreference {
+    pub fn to_tauri_theme(self) -> Option<tauri::Theme> {
+        match self {
+            ThemePreference::Light => Some(tauri::Theme::Light),
+            ThemePreference::Dark => Some(tauri::Theme::Dark),
+            ThemePreference::Auto => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowState {
+    pub position: PhysicalPosition<i32>,
+    pub size: PhysicalSize<u32>,
+    pub maximized: bool,
+}
+
+/// Loads the last saved window state, if any was ever written.
+pub fn load(app: &AppHandle) -> Option<WindowState> {
+    let path = state_file_path(app).ok()?;
+    let data = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+/// Writes `state` to the app config dir, overwriting whatever was there before.
+pub fn save(app: &AppHandle, state: &WindowState) {
+    let Ok(path) = state_file_path(app) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(data) = serde_json::to_string_pretty(state) {
+        let _ = fs::write(path, data);
+    }
+}
+
+/// Returns `true` if `position` falls within the bounds of a monitor that's currently attached,
+/// so we don't restore a window to a spot on a display that's since been unplugged.
+pub fn is_on_screen(app: &AppHandle, position: &PhysicalPosition<i32>) -> bool {
+    let Ok(monitors) = app.available_monitors() else {
+        return false;
+    };
+    monitors.iter().any(|monitor| {
+        let m_pos = monitor.position();
+        let m_size = monitor.size();
+        position.x >= m_pos.x
+            && position.y >= m_pos.y
+            && position.x < m_pos.x + m_size.width as i32
+            && position.y < m_pos.y + m_size.height as i32
+    })
+}
+
+fn state_file_path(app: &AppHandle) -> tauri::Result<PathBuf> {
+    Ok(app.path().app_config_dir()?.join(WINDOW_STATE_FILE))
+}
+
+/// Loads the saved theme preference, defaulting to `Auto` on first launch.
+pub fn load_theme(app: &AppHandle) -> ThemePreference {
+    (|| {
+        let path = theme_file_path(app).ok()?;
+        let data = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&data).ok()
+    })()
+    .unwrap_or_default()
+}
+
+/// Persists the theme preference alongside the window-state file.
+pub fn save_theme(app: &AppHandle, theme: ThemePreference) {
+    let Ok(path) = theme_file_path(app) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(data) = serde_json::to_string_pretty(&theme) {
+        let _ = fs::write(path, data);
+    }
+}
+
+fn theme_file_path(app: &AppHandle) -> tauri::Result<PathBuf> {
+    Ok(app.path().app_config_dir()?.join(THEME_FILE))
+}